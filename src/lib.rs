@@ -11,16 +11,88 @@ extern crate serde_derive;
 pub mod email;
 pub mod validation;
 
+use idna::domain_to_ascii;
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::fmt;
+use unicode_normalization::UnicodeNormalization;
 
 pub use reqwest::Error as ReqError;
 
 const MAILGUN_DEFAULT_API: &str = "https://api.mailgun.net/v3";
 
-///! Wrapper result type returning `reqwest` errors
-pub type MailgunResult<T> = Result<T, ReqError>;
+///! Wrapper result type returning a [`MailgunError`]
+pub type MailgunResult<T> = Result<T, MailgunError>;
+
+/// An invalid `EmailAddress`, distinguishing which part of the address
+/// failed validation so callers can match on the failure mode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EmailError {
+    /// The address did not contain an unquoted `@`.
+    MissingAt,
+    /// The local-part (before the `@`) is not a valid dot-atom or quoted string.
+    IncorrectLocalPart,
+    /// The domain (after the `@`) is not a valid dot-atom or IP literal.
+    IncorrectDomainPart,
+    /// The display name is empty or contains a `<` or `>`.
+    InvalidDisplayName,
+    /// The whole address exceeds its RFC 5322 length limit (254 characters).
+    /// An over-long local-part or domain is reported as `IncorrectLocalPart`
+    /// or `IncorrectDomainPart`.
+    TooLong,
+}
+
+impl fmt::Display for EmailError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EmailError::MissingAt => write!(f, "address is missing an '@'"),
+            EmailError::IncorrectLocalPart => write!(f, "incorrect local part"),
+            EmailError::IncorrectDomainPart => write!(f, "incorrect domain part"),
+            EmailError::InvalidDisplayName => write!(f, "invalid display name"),
+            EmailError::TooLong => write!(f, "address is too long"),
+        }
+    }
+}
+
+impl std::error::Error for EmailError {}
+
+/// The crate-wide error type, unifying `EmailAddress` validation failures
+/// with transport failures from `reqwest`.
+#[derive(Debug)]
+pub enum MailgunError {
+    Email(EmailError),
+    Request(ReqError),
+}
+
+impl fmt::Display for MailgunError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MailgunError::Email(err) => write!(f, "{}", err),
+            MailgunError::Request(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for MailgunError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MailgunError::Email(err) => Some(err),
+            MailgunError::Request(err) => Some(err),
+        }
+    }
+}
+
+impl From<EmailError> for MailgunError {
+    fn from(err: EmailError) -> Self {
+        MailgunError::Email(err)
+    }
+}
+
+impl From<ReqError> for MailgunError {
+    fn from(err: ReqError) -> Self {
+        MailgunError::Request(err)
+    }
+}
 
 ///! Mailgun private API key and sending domain
 #[derive(Debug, Clone)]
@@ -73,8 +145,9 @@ pub struct EmailAddress {
     address: String,
 }
 
-// TODO: introduce address validation (RFC5322 + RFC5198 + RFC6532)
-// Could consider using the email-address-parser crate (or similar).
+const MAX_LOCAL_PART_LEN: usize = 64;
+const MAX_DOMAIN_LEN: usize = 255;
+const MAX_ADDRESS_LEN: usize = 254;
 
 impl EmailAddress {
     pub fn address<T: ToString>(address: T) -> Self {
@@ -91,9 +164,129 @@ impl EmailAddress {
         }
     }
 
+    /// Builds an `EmailAddress` from an already-split local-part and domain,
+    /// validating both independently per RFC 5322.
+    pub fn new<L: ToString, D: ToString>(local_part: L, domain: D) -> Result<Self, EmailError> {
+        let local_part = local_part.to_string();
+        let domain = domain.to_string();
+        let address = format!("{}@{}", local_part, domain);
+
+        if address.len() > MAX_ADDRESS_LEN {
+            return Err(EmailError::TooLong);
+        }
+        if !is_valid_local_part(&local_part) {
+            return Err(EmailError::IncorrectLocalPart);
+        }
+        if !is_valid_domain(&domain) {
+            return Err(EmailError::IncorrectDomainPart);
+        }
+
+        Ok(EmailAddress {
+            name: None,
+            address,
+        })
+    }
+
     pub fn email(&self) -> &str {
         &self.address
     }
+
+    /// The part of the address before the last unquoted `@`.
+    pub fn local_part(&self) -> &str {
+        split_address(&self.address)
+            .map(|(local, _)| local)
+            .unwrap_or(&self.address)
+    }
+
+    /// The part of the address after the last unquoted `@`.
+    pub fn domain(&self) -> &str {
+        split_address(&self.address)
+            .map(|(_, domain)| domain)
+            .unwrap_or("")
+    }
+
+    /// Parses an RFC 5322 comma-separated address list, such as a `To` or
+    /// `Cc` header value (`Bob Test <bob@x.com>, alice@y.org`), into
+    /// individual addresses. Commas inside quoted display names or
+    /// angle-bracketed addresses do not split the list.
+    pub fn parse_list(input: &str) -> Result<Vec<EmailAddress>, EmailError> {
+        split_address_list(input)
+            .iter()
+            .map(|mailbox| EmailAddress::try_from(mailbox.trim()))
+            .collect()
+    }
+
+    /// Parses `input` as an internationalized email address (RFC 6531/6532):
+    /// unlike [`TryFrom<&str>`], the local-part may contain non-ASCII
+    /// characters and the domain may contain non-ASCII labels. This is
+    /// opt-in; `TryFrom` remains ASCII-only.
+    pub fn parse_international(input: &str) -> Result<EmailAddress, EmailError> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(r"^(.*) <([^>]+)>$").unwrap();
+        }
+
+        let result = match RE.captures(input) {
+            Some(captures) if captures.len() == 3 => EmailAddress::name_address(
+                captures.get(1).unwrap().as_str(),
+                captures.get(2).unwrap().as_str(),
+            ),
+            _ => EmailAddress::address(input),
+        };
+
+        if let Some(ref name) = result.name {
+            if !is_valid_display_name(name) {
+                return Err(EmailError::InvalidDisplayName);
+            }
+        }
+
+        let (local_part, domain) = split_address(&result.address).ok_or(EmailError::MissingAt)?;
+        let local_part: String = local_part.nfc().collect();
+
+        if !is_valid_international_local_part(&local_part) {
+            return Err(EmailError::IncorrectLocalPart);
+        }
+        if domain.is_empty() {
+            return Err(EmailError::IncorrectDomainPart);
+        }
+        if !is_valid_domain(domain) {
+            // `domain` is not a valid ASCII domain (it may contain non-ASCII
+            // labels); convert to punycode and
+            // validate the result structurally rather than just checking
+            // that IDNA didn't error (it accepts e.g. empty or
+            // trailing-dot/space-containing domains under its default,
+            // non-STD3 config).
+            let ascii_domain = domain_to_ascii(domain).map_err(|_| EmailError::IncorrectDomainPart)?;
+            if !is_valid_domain(&ascii_domain) {
+                return Err(EmailError::IncorrectDomainPart);
+            }
+        }
+
+        let address = format!("{}@{}", local_part, domain);
+        if address.len() > MAX_ADDRESS_LEN {
+            return Err(EmailError::TooLong);
+        }
+
+        Ok(EmailAddress {
+            name: result.name,
+            address,
+        })
+    }
+
+    /// Whether this address requires the Mailgun SMTPUTF8 extension to send,
+    /// i.e. its local-part contains non-ASCII characters.
+    pub fn requires_smtputf8(&self) -> bool {
+        !self.local_part().is_ascii()
+    }
+
+    /// Converts the domain to its ASCII-compatible (punycode, `xn--`) form
+    /// for wire transmission, leaving the local-part untouched. Callers
+    /// should pair this with [`EmailAddress::requires_smtputf8`] to decide
+    /// whether the SMTPUTF8 extension is also needed.
+    pub fn to_ascii(&self) -> Result<String, EmailError> {
+        let ascii_domain =
+            domain_to_ascii(self.domain()).map_err(|_| EmailError::IncorrectDomainPart)?;
+        Ok(format!("{}@{}", self.local_part(), ascii_domain))
+    }
 }
 
 impl fmt::Display for EmailAddress {
@@ -113,20 +306,201 @@ fn is_valid_display_name(name: &str) -> bool {
     RE.is_match(name)
 }
 
-/// Basic validation of address.
-fn is_valid_address(address: &str) -> bool {
-    lazy_static! {
-        // TODO: use a proper regex
-        static ref RE: Regex = Regex::new(r"^[^<> ]+@[^<> ]+\.[^<> ]+$").unwrap();
+/// Splits an RFC 5322 address list on top-level commas, i.e. commas that
+/// are not inside a quoted display name (`"..."`) or an angle-bracketed
+/// address (`<...>`).
+fn split_address_list(input: &str) -> Vec<&str> {
+    let mut mailboxes = Vec::new();
+    let mut in_quotes = false;
+    let mut angle_depth = 0u32;
+    let mut escaped = false;
+    let mut start = 0;
+
+    for (idx, ch) in input.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+        match ch {
+            '\\' if in_quotes => escaped = true,
+            '"' => in_quotes = !in_quotes,
+            '<' if !in_quotes => angle_depth += 1,
+            '>' if !in_quotes && angle_depth > 0 => angle_depth -= 1,
+            ',' if !in_quotes && angle_depth == 0 => {
+                mailboxes.push(&input[start..idx]);
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    mailboxes.push(&input[start..]);
+
+    mailboxes
+}
+
+/// Splits an address at the last unquoted `@`, so that `@` characters inside
+/// a quoted local-part (e.g. `"a@b"@example.com`) are not mistaken for the
+/// local-part/domain separator.
+fn split_address(address: &str) -> Option<(&str, &str)> {
+    let mut in_quotes = false;
+    let mut escaped = false;
+    let mut last_at = None;
+
+    for (idx, ch) in address.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+        match ch {
+            '\\' if in_quotes => escaped = true,
+            '"' => in_quotes = !in_quotes,
+            '@' if !in_quotes => last_at = Some(idx),
+            _ => {}
+        }
+    }
+
+    last_at.map(|idx| (&address[..idx], &address[idx + 1..]))
+}
+
+/// Validates an RFC 5322 local-part: either a dot-atom of `atext` tokens
+/// (no leading, trailing, or consecutive dots), or a quoted string.
+fn is_valid_local_part(local_part: &str) -> bool {
+    if local_part.is_empty() || local_part.len() > MAX_LOCAL_PART_LEN {
+        return false;
     }
-    RE.is_match(address)
+
+    if local_part.starts_with('"') && local_part.ends_with('"') && local_part.len() >= 2 {
+        return is_valid_quoted_string(&local_part[1..local_part.len() - 1]);
+    }
+
+    if local_part.starts_with('.')
+        || local_part.ends_with('.')
+        || local_part.contains("..")
+    {
+        return false;
+    }
+
+    local_part.split('.').all(|atom| {
+        !atom.is_empty() && atom.chars().all(is_atext)
+    })
+}
+
+/// `atext` as defined by RFC 5322 section 3.2.3.
+fn is_atext(c: char) -> bool {
+    c.is_ascii_alphanumeric()
+        || "!#$%&'*+-/=?^_`{|}~".contains(c)
+}
+
+/// A quoted-string body (RFC 5322 section 3.2.4): printable ASCII and
+/// spaces, with `\` escaping the following character.
+fn is_valid_quoted_string(body: &str) -> bool {
+    let mut escaped = false;
+    for c in body.chars() {
+        if escaped {
+            if !c.is_ascii() || c.is_ascii_control() {
+                return false;
+            }
+            escaped = false;
+            continue;
+        }
+        match c {
+            '\\' => escaped = true,
+            '"' => return false,
+            _ if c.is_ascii() && (!c.is_ascii_control() || c == ' ') => {}
+            _ => return false,
+        }
+    }
+    !escaped
+}
+
+/// Validates an RFC 5322 domain: either a dot-atom of labels (each 1-63
+/// characters, no leading/trailing hyphen), or a bracketed IP literal.
+fn is_valid_domain(domain: &str) -> bool {
+    if domain.is_empty() || domain.len() > MAX_DOMAIN_LEN {
+        return false;
+    }
+
+    if domain.starts_with('[') && domain.ends_with(']') {
+        return domain[1..domain.len() - 1].parse::<std::net::IpAddr>().is_ok();
+    }
+
+    if domain.starts_with('.') || domain.ends_with('.') || domain.contains("..") {
+        return false;
+    }
+
+    domain.split('.').all(is_valid_domain_label)
+}
+
+fn is_valid_domain_label(label: &str) -> bool {
+    !label.is_empty()
+        && label.len() <= 63
+        && !label.starts_with('-')
+        && !label.ends_with('-')
+        && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
+
+/// Validates an RFC 6531 international local-part: like
+/// [`is_valid_local_part`] but `atext` is extended to any non-control,
+/// non-bidi-control character, to allow Unicode local-parts.
+fn is_valid_international_local_part(local_part: &str) -> bool {
+    if local_part.is_empty() || local_part.chars().count() > MAX_LOCAL_PART_LEN {
+        return false;
+    }
+
+    if local_part.starts_with('"') && local_part.ends_with('"') && local_part.chars().count() >= 2 {
+        let body: String = local_part.chars().skip(1).take(local_part.chars().count() - 2).collect();
+        return is_valid_quoted_string(&body) || body.chars().all(is_international_atext);
+    }
+
+    if local_part.starts_with('.') || local_part.ends_with('.') || local_part.contains("..") {
+        return false;
+    }
+
+    local_part
+        .split('.')
+        .all(|atom| !atom.is_empty() && atom.chars().all(is_international_atext))
+}
+
+/// `atext` per RFC 6531: anything allowed by RFC 5322's ASCII `atext`, plus
+/// any non-ASCII character that isn't a control or bidi-control character.
+fn is_international_atext(c: char) -> bool {
+    if c.is_ascii() {
+        return is_atext(c);
+    }
+    !c.is_control() && !is_bidi_control(c)
+}
+
+/// Explicit bidirectional-formatting control characters (e.g. RLO/LRO/PDF),
+/// which could otherwise be used to spoof the visual order of an address.
+fn is_bidi_control(c: char) -> bool {
+    matches!(
+        c,
+        '\u{200e}' | '\u{200f}' | '\u{202a}'..='\u{202e}' | '\u{2066}'..='\u{2069}'
+    )
+}
+
+/// Validates a full address, reporting which part failed so callers can
+/// match on the specific [`EmailError`] variant.
+fn validate_address(address: &str) -> Result<(), EmailError> {
+    if address.len() > MAX_ADDRESS_LEN {
+        return Err(EmailError::TooLong);
+    }
+    let (local_part, domain) = split_address(address).ok_or(EmailError::MissingAt)?;
+    if !is_valid_local_part(local_part) {
+        return Err(EmailError::IncorrectLocalPart);
+    }
+    if !is_valid_domain(domain) {
+        return Err(EmailError::IncorrectDomainPart);
+    }
+    Ok(())
 }
 
 impl<'a> TryFrom<&'a str> for EmailAddress {
-    type Error = &'static str;
+    type Error = EmailError;
 
-    /// This parser does not validate the emails, just tries to parse according to
-    /// a minimal subset of the RFC5322 rules.
+    /// Parses an RFC 5322 mailbox (`local@domain` or `Name <local@domain>`),
+    /// validating the local-part and domain independently; ASCII only. See
+    /// [`EmailAddress::parse_international`] for a Unicode-aware variant.
     fn try_from(input: &str) -> Result<EmailAddress, Self::Error> {
         lazy_static! {
             static ref RE: Regex = Regex::new(r"^(.*) <([^>]+)>$").unwrap();
@@ -148,15 +522,12 @@ impl<'a> TryFrom<&'a str> for EmailAddress {
 
         if let Some(ref name) = result.name {
             if !is_valid_display_name(name) {
-                return Err("Invalid display name");
+                return Err(EmailError::InvalidDisplayName);
             }
         }
 
-        if !is_valid_address(&result.address) {
-            Err("Invalid email address")
-        } else {
-            Ok(result)
-        }
+        validate_address(&result.address)?;
+        Ok(result)
     }
 }
 
@@ -180,18 +551,171 @@ mod tests {
         }
 
         let failure_cases = vec![
-            ("test", "Invalid email address"),
-            ("@email.com", "Invalid email address"),
-            ("Bob Test", "Invalid email address"),
-            ("Bob Test <>", "Invalid email address"),
-            ("Bob Test <test>", "Invalid email address"),
-            ("Bob Test <@email.com>", "Invalid email address"),
-            ("<Bob Test> <test@email.com>", "Invalid display name"),
+            ("test", EmailError::MissingAt),
+            ("@email.com", EmailError::IncorrectLocalPart),
+            ("Bob Test", EmailError::MissingAt),
+            ("Bob Test <>", EmailError::MissingAt),
+            ("Bob Test <test>", EmailError::MissingAt),
+            ("Bob Test <@email.com>", EmailError::IncorrectLocalPart),
+            ("<Bob Test> <test@email.com>", EmailError::InvalidDisplayName),
+            (".test@email.com", EmailError::IncorrectLocalPart),
+            ("te..st@email.com", EmailError::IncorrectLocalPart),
+            ("test@-email.com", EmailError::IncorrectDomainPart),
+            ("test@email.", EmailError::IncorrectDomainPart),
         ];
         for (input, expected) in failure_cases {
             let result = EmailAddress::try_from(input);
-            assert!(result.is_err());
             assert_eq!(result.err(), Some(expected));
         }
     }
+
+    #[test]
+    fn build_email_address_from_parts() {
+        let address = EmailAddress::new("test", "email.com").unwrap();
+        assert_eq!(address.local_part(), "test");
+        assert_eq!(address.domain(), "email.com");
+        assert_eq!(address.email(), "test@email.com");
+
+        assert!(EmailAddress::new("test", "-email.com").is_err());
+        assert!(EmailAddress::new("", "email.com").is_err());
+    }
+
+    #[test]
+    fn bracketed_ip_literal_domain_is_valid() {
+        let result = EmailAddress::try_from("test@[192.168.1.1]");
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn international_local_part_requires_smtputf8() {
+        let address = EmailAddress::parse_international("あいうえお@example.com").unwrap();
+        assert_eq!(address.local_part(), "あいうえお");
+        assert!(address.requires_smtputf8());
+    }
+
+    #[test]
+    fn international_domain_is_converted_to_ascii() {
+        let address = EmailAddress::parse_international("test@münchen.de").unwrap();
+        assert!(!address.requires_smtputf8());
+        assert_eq!(address.to_ascii().unwrap(), "test@xn--mnchen-3ya.de");
+    }
+
+    #[test]
+    fn international_local_part_rejects_bidi_control_chars() {
+        let result = EmailAddress::parse_international("test\u{202e}@example.com");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn international_parse_rejects_malformed_domains() {
+        assert_eq!(
+            EmailAddress::parse_international("test@").err(),
+            Some(EmailError::IncorrectDomainPart)
+        );
+        assert_eq!(
+            EmailAddress::parse_international("test@münchen.").err(),
+            Some(EmailError::IncorrectDomainPart)
+        );
+        assert_eq!(
+            EmailAddress::parse_international("test@mün chen.de").err(),
+            Some(EmailError::IncorrectDomainPart)
+        );
+    }
+
+    #[test]
+    fn parse_address_list() {
+        let result =
+            EmailAddress::parse_list(r#"Bob Test <bob@x.com>, alice@y.org, "Do, Re" <dr@z.net>"#)
+                .unwrap();
+        assert_eq!(
+            result,
+            vec![
+                EmailAddress::name_address("Bob Test", "bob@x.com"),
+                EmailAddress::address("alice@y.org"),
+                EmailAddress::name_address("\"Do, Re\"", "dr@z.net"),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_address_list_propagates_invalid_mailbox() {
+        let result = EmailAddress::parse_list("bob@x.com, not-an-address");
+        assert_eq!(result.err(), Some(EmailError::MissingAt));
+    }
+
+    #[test]
+    fn mailgun_error_wraps_email_error() {
+        let err: MailgunError = EmailError::TooLong.into();
+        assert_eq!(err.to_string(), "address is too long");
+    }
+}
+
+/// Property-based tests backing the parser's contract: every address `fake`
+/// generates as valid must round-trip through `EmailAddress::try_from`, and
+/// every address `MutatedAddress` has corrupted in a way that violates
+/// RFC 5322 must be rejected.
+#[cfg(test)]
+mod quickcheck_tests {
+    use super::*;
+    use fake::faker::internet::en::{FreeEmail, SafeEmail};
+    use fake::Fake;
+    use quickcheck::{quickcheck, Arbitrary, Gen, TestResult};
+
+    #[derive(Debug, Clone)]
+    struct ValidAddress(String);
+
+    impl Arbitrary for ValidAddress {
+        fn arbitrary(g: &mut Gen) -> Self {
+            let address: String = if bool::arbitrary(g) {
+                SafeEmail().fake()
+            } else {
+                FreeEmail().fake()
+            };
+            ValidAddress(address)
+        }
+    }
+
+    /// A valid, `fake`-generated address mutated into an invalid one, or an
+    /// address whose local-part is one character over the 64-character limit.
+    #[derive(Debug, Clone)]
+    struct MutatedAddress(String);
+
+    impl Arbitrary for MutatedAddress {
+        fn arbitrary(g: &mut Gen) -> Self {
+            let address: String = SafeEmail().fake();
+            let mutated = match u8::arbitrary(g) % 5 {
+                0 => address.replacen('@', "", 1),
+                1 => format!(" {}", address),
+                2 => address.replacen('.', "..", 1),
+                3 => format!(".{}", address),
+                _ => format!("{}@example.com", "a".repeat(MAX_LOCAL_PART_LEN + 1)),
+            };
+            MutatedAddress(mutated)
+        }
+    }
+
+    quickcheck! {
+        /// Every address `fake` considers valid must parse, and its
+        /// local-part/domain must match what a naive split produces. The
+        /// expected parts are derived independently of `split_address` (via
+        /// `rsplit_once`) so a real parser bug can actually be caught, not
+        /// just re-confirmed by the same code path under test.
+        fn valid_addresses_round_trip(address: ValidAddress) -> TestResult {
+            let (expected_local, expected_domain) = match address.0.rsplit_once('@') {
+                Some(parts) => parts,
+                None => return TestResult::discard(),
+            };
+            match EmailAddress::try_from(address.0.as_str()) {
+                Ok(parsed) => TestResult::from_bool(
+                    parsed.local_part() == expected_local && parsed.domain() == expected_domain,
+                ),
+                Err(_) => TestResult::failed(),
+            }
+        }
+
+        /// Every address corrupted by `MutatedAddress` must be rejected.
+        fn mutated_addresses_are_rejected(address: MutatedAddress) -> TestResult {
+            TestResult::from_bool(EmailAddress::try_from(address.0.as_str()).is_err())
+        }
+    }
 }